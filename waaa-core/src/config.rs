@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Config {
+    pub pishock_config: PishockConfig,
+    /// IDs of the users whose mentions always trigger a shock, regardless of `trigger_words`.
+    /// Platform-neutral: a [`ChatPlatform`](crate::platform::ChatPlatform) frontend maps its own
+    /// notion of a user to this same `u64` space.
+    pub operator_ids: Vec<u64>,
+    pub trigger_words: Vec<TriggerWord>,
+    pub cooldown_segment_duration: u32,
+    pub max_shocks_per_segment: u32,
+    /// Connection details for the optional Postgres persistence layer. When omitted, shock
+    /// cooldowns live only in memory and no audit log is kept, matching the prior behavior.
+    pub database_config: Option<DatabaseConfig>,
+    /// Port to serve Prometheus metrics on at `/metrics`. When omitted, metrics are still
+    /// collected in-process but no HTTP server is started.
+    pub metrics_port: Option<u16>,
+    /// The intensity sent to the PiShock API for every shock, absent any per-trigger override.
+    pub default_intensity: u32,
+    /// The duration, in seconds, sent to the PiShock API for every shock, absent any
+    /// per-trigger override.
+    pub default_duration_secs: u32,
+    /// Path to bind the operator control socket at. When omitted, the control socket is not
+    /// started and the bot can only be tuned by editing `config.ron` and restarting.
+    pub control_socket_path: Option<String>,
+    /// Ramps intensity up within a segment instead of sending a flat intensity for every
+    /// shock. When omitted, every shock in a segment uses the same (trigger or default)
+    /// intensity.
+    pub escalation: Option<EscalationConfig>,
+}
+
+/// A word that triggers a shock, with an optional intensity/duration override. Absent fields
+/// fall back to `Config::default_intensity` / `Config::default_duration_secs`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TriggerWord {
+    pub word: String,
+    pub intensity: Option<u32>,
+    pub duration_secs: Option<u32>,
+}
+
+/// Ramps intensity up the more a user is shocked within a single cooldown segment. The
+/// intensity for the Nth shock in a segment is `base + step * N`, clamped to `max_intensity`,
+/// where `base` is the intensity that would otherwise have been used. Resets when the segment
+/// rolls over.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EscalationConfig {
+    pub step: u32,
+    pub max_intensity: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PishockConfig {
+    pub api_name: String,
+    pub api_username: String,
+    pub api_key: String,
+    pub share_code: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DatabaseConfig {
+    pub host: String,
+    pub port: u16,
+    pub user: String,
+    pub password: String,
+    pub dbname: String,
+}
+
+impl std::fmt::Debug for DatabaseConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabaseConfig")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("user", &self.user)
+            .field("password", &"[redacted]")
+            .field("dbname", &self.dbname)
+            .finish()
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+
+/// The number of shocks a user has dealt during the current span of time.
+#[derive(Debug, Clone)]
+pub struct ShockCooldown {
+    /// The timestamp that the current segment started at.
+    segment_start: DateTime<Utc>,
+    /// The number of times the user has dealt a shock during the last block.
+    shock_count: u32,
+}
+
+impl ShockCooldown {
+    /// Starts a fresh cooldown segment with no shocks dealt yet.
+    pub fn new() -> Self {
+        Self {
+            segment_start: Utc::now(),
+            shock_count: 0,
+        }
+    }
+
+    /// Reconstructs a cooldown segment previously persisted to a frontend's database.
+    pub fn from_persisted(segment_start: DateTime<Utc>, shock_count: u32) -> Self {
+        Self {
+            segment_start,
+            shock_count,
+        }
+    }
+
+    pub fn segment_start(&self) -> DateTime<Utc> {
+        self.segment_start
+    }
+
+    pub fn shock_count(&self) -> u32 {
+        self.shock_count
+    }
+
+    /// Records that a shock was just dealt during the current segment.
+    pub fn record_shock(&mut self) {
+        self.shock_count += 1;
+    }
+
+    /// Are there room for more shocks during the current segment? Returns true if the cooldown has room for the shock. Returns false if too many shocks have already been dealt.
+    ///
+    /// # Parameters
+    /// * `segment_length` - The amount of time between segment resets.
+    /// * `maximum_shocks` - The maximum number of shocks allowed before a segment reset.
+    pub fn can_shock(&mut self, segment_length: std::time::Duration, maximum_shocks: u32) -> bool {
+        // Reset the segment start and shock_count if the segment_length has been reached.
+        if let Ok(segment_length) = chrono::Duration::from_std(segment_length) {
+            if Utc::now() - self.segment_start >= segment_length {
+                self.segment_start = Utc::now();
+                self.shock_count = 0;
+            }
+        }
+
+        // Check to see if the shock_count is below or equal to maximum. Return true if so, and increment shock_count.
+        self.shock_count < maximum_shocks
+    }
+}
+
+impl Default for ShockCooldown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,4 @@
+pub mod config;
+pub mod cooldown;
+pub mod platform;
+pub mod shock;
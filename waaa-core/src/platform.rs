@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+/// A user saying something in a channel, plus the ability to reply to it. Implementing this
+/// for a chat platform's native message type is all a frontend needs to reuse the core shock
+/// and cooldown semantics.
+#[async_trait]
+pub trait ChatPlatform {
+    /// Opaque identifier for the user who sent the message.
+    fn user_id(&self) -> u64;
+
+    /// The message text to run trigger-word matching against.
+    fn content(&self) -> &str;
+
+    /// True if the message mentions the given user.
+    fn mentions_user(&self, user_id: u64) -> bool;
+
+    /// Sends a text reply back to wherever the message came from.
+    async fn reply(&self, text: &str);
+}
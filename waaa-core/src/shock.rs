@@ -0,0 +1,228 @@
+use std::{collections::HashMap, time::Duration};
+
+use log::{debug, info, trace};
+use pishock_rs::PiShocker;
+use regex::Regex;
+
+use crate::{
+    config::{Config, EscalationConfig},
+    cooldown::ShockCooldown,
+    platform::ChatPlatform,
+};
+
+/// The trigger/cooldown verdict for a message, before any PiShock API call is made.
+pub enum Decision {
+    /// No trigger matched, or the bot is paused.
+    NoMatch,
+    /// The user was still within an active cooldown segment.
+    CooldownRejected { seconds_until_reset: u64 },
+    /// The message should be shocked for. `trigger_word` is `None` when an operator mention,
+    /// rather than a trigger word, caused it.
+    Shock {
+        cause: &'static str,
+        trigger_word: Option<String>,
+        /// The matched trigger word's intensity override, if it has one.
+        intensity_override: Option<u32>,
+        /// The matched trigger word's duration override, if it has one.
+        duration_override: Option<u32>,
+        /// How many shocks the user had already been dealt this segment, before this one.
+        shocks_this_segment: u32,
+    },
+}
+
+/// What happened when [`decide_and_shock`] processed a message, so a frontend can update its
+/// own metrics and persistence without re-deriving the decision.
+pub enum ShockOutcome {
+    NoMatch,
+    CooldownRejected {
+        seconds_until_reset: u64,
+    },
+    Shocked {
+        cause: &'static str,
+        trigger_word: Option<String>,
+        intensity: u32,
+        duration: Duration,
+        /// Wall-clock time the call to the PiShock API took.
+        latency: Duration,
+    },
+}
+
+/// A trigger match found in a message, before cooldowns are considered.
+struct Match {
+    cause: &'static str,
+    trigger_word: Option<String>,
+    intensity_override: Option<u32>,
+    duration_override: Option<u32>,
+}
+
+/// Checks whether `msg` mentions an operator or contains a trigger word, without touching
+/// cooldown state. Cheap and synchronous, so a frontend can call it to decide whether a message
+/// is even worth the cost of loading that user's cooldown (e.g. from a database) before calling
+/// [`evaluate`].
+fn find_match<P: ChatPlatform>(msg: &P, config: &Config) -> Option<Match> {
+    // If the message mentions any of the bot's operators, shock.
+    if config.operator_ids.iter().any(|x| msg.mentions_user(*x)) {
+        trace!("Message mentions bot owner. Shock impending...");
+        return Some(Match {
+            cause: "mention",
+            trigger_word: None,
+            intensity_override: None,
+            duration_override: None,
+        });
+    }
+
+    // If any of the words in the message are a trigger word, shock.
+    let split_sentence = Regex::new(r"(\b[^\s]+\b)").unwrap();
+    for word in split_sentence.captures_iter(msg.content()) {
+        let word_lowercase = word.get(0).unwrap().as_str().to_lowercase();
+        if let Some(trigger) = config
+            .trigger_words
+            .iter()
+            .find(|x| x.word == word_lowercase)
+        {
+            trace!("Caught trigger word \"{word_lowercase}\". Shock impending...");
+            return Some(Match {
+                cause: "trigger_word",
+                trigger_word: Some(word_lowercase),
+                intensity_override: trigger.intensity,
+                duration_override: trigger.duration_secs,
+            });
+        }
+    }
+
+    trace!("Message does not match shock parameters.");
+    None
+}
+
+/// Checks whether `msg` would shock if [`evaluate`] were called right now, without requiring the
+/// user's cooldown state to already be loaded. Intended as a pre-check so a frontend can skip
+/// loading a user's cooldown (e.g. from a database) for messages that will never match.
+pub fn would_match<P: ChatPlatform>(msg: &P, config: &Config, paused: bool) -> bool {
+    !paused && find_match(msg, config).is_some()
+}
+
+/// Evaluates whether `msg` should trigger a shock and applies the user's cooldown. Pure aside
+/// from `msg.reply(...)` on a cooldown rejection, so it can be exercised with a mock
+/// [`ChatPlatform`] without a live PiShock connection or chat gateway.
+pub async fn evaluate<P: ChatPlatform + Sync>(
+    msg: &P,
+    config: &Config,
+    cooldowns: &mut HashMap<u64, ShockCooldown>,
+    paused: bool,
+) -> Decision {
+    if paused {
+        trace!("Bot is paused. Ignoring message.");
+        return Decision::NoMatch;
+    }
+
+    let Some(Match {
+        cause: shock_cause,
+        trigger_word: matched_trigger_word,
+        intensity_override,
+        duration_override,
+    }) = find_match(msg, config)
+    else {
+        return Decision::NoMatch;
+    };
+
+    let cooldown = cooldowns.entry(msg.user_id()).or_default();
+
+    if !cooldown.can_shock(
+        Duration::from_secs(config.cooldown_segment_duration as u64),
+        config.max_shocks_per_segment,
+    ) {
+        // The number of seconds until the segment counter is reset.
+        let seconds_until_reset = config.cooldown_segment_duration as u64
+            - (chrono::Utc::now() - cooldown.segment_start())
+                .num_seconds()
+                .max(0) as u64;
+
+        debug!(
+            "User has exceeded shock limit for the current segment {}/{} ({} seconds remaining)",
+            cooldown.shock_count(),
+            config.max_shocks_per_segment,
+            seconds_until_reset,
+        );
+
+        msg.reply(&format!("Wait {} seconds...", seconds_until_reset))
+            .await;
+
+        return Decision::CooldownRejected {
+            seconds_until_reset,
+        };
+    }
+
+    let shocks_this_segment = cooldown.shock_count();
+    cooldown.record_shock();
+
+    Decision::Shock {
+        cause: shock_cause,
+        trigger_word: matched_trigger_word,
+        intensity_override,
+        duration_override,
+        shocks_this_segment,
+    }
+}
+
+/// Ramps `intensity` by `escalation.step` for each shock already dealt this segment, clamped to
+/// `escalation.max_intensity`. Pure so it can be unit tested without a live PiShock connection.
+pub fn escalate_intensity(
+    intensity: u32,
+    shocks_this_segment: u32,
+    escalation: &EscalationConfig,
+) -> u32 {
+    let escalated = intensity.saturating_add(escalation.step.saturating_mul(shocks_this_segment));
+    escalated.min(escalation.max_intensity)
+}
+
+/// Runs [`evaluate`] and, if it decides to shock, calls the PiShock API through `shocker`. This
+/// is the single protocol-agnostic decision point shared by every [`ChatPlatform`] frontend.
+///
+/// The intensity sent is the matched trigger's override (or `config.default_intensity`), then,
+/// if `config.escalation` is set, ramped by `step * shocks_this_segment` and clamped to
+/// `max_intensity`.
+pub async fn decide_and_shock<P: ChatPlatform + Sync>(
+    msg: &P,
+    config: &Config,
+    cooldowns: &mut HashMap<u64, ShockCooldown>,
+    shocker: &PiShocker,
+    paused: bool,
+) -> ShockOutcome {
+    match evaluate(msg, config, cooldowns, paused).await {
+        Decision::NoMatch => ShockOutcome::NoMatch,
+        Decision::CooldownRejected {
+            seconds_until_reset,
+        } => ShockOutcome::CooldownRejected {
+            seconds_until_reset,
+        },
+        Decision::Shock {
+            cause,
+            trigger_word,
+            intensity_override,
+            duration_override,
+            shocks_this_segment,
+        } => {
+            let mut intensity = intensity_override.unwrap_or(config.default_intensity);
+            let duration = Duration::from_secs(
+                duration_override.unwrap_or(config.default_duration_secs) as u64,
+            );
+
+            if let Some(escalation) = &config.escalation {
+                intensity = escalate_intensity(intensity, shocks_this_segment, escalation);
+            }
+
+            info!("Shocking! (cause={cause}, word={trigger_word:?}, intensity={intensity})");
+            let shock_start = std::time::Instant::now();
+            shocker.shock(intensity, duration).await.unwrap();
+            let latency = shock_start.elapsed();
+
+            ShockOutcome::Shocked {
+                cause,
+                trigger_word,
+                intensity,
+                duration,
+                latency,
+            }
+        }
+    }
+}
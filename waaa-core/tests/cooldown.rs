@@ -0,0 +1,216 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use waaa_core::{
+    config::{Config, EscalationConfig, PishockConfig, TriggerWord},
+    cooldown::ShockCooldown,
+    platform::ChatPlatform,
+    shock::{escalate_intensity, evaluate, Decision},
+};
+
+/// A `ChatPlatform` standing in for a real chat gateway, so the trigger/cooldown logic can be
+/// exercised without Discord.
+struct MockMessage {
+    user_id: u64,
+    content: String,
+    mentioned: Vec<u64>,
+    replies: Mutex<Vec<String>>,
+}
+
+impl MockMessage {
+    fn new(user_id: u64, content: &str) -> Self {
+        Self {
+            user_id,
+            content: content.to_owned(),
+            mentioned: Vec::new(),
+            replies: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl ChatPlatform for MockMessage {
+    fn user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    fn content(&self) -> &str {
+        &self.content
+    }
+
+    fn mentions_user(&self, user_id: u64) -> bool {
+        self.mentioned.contains(&user_id)
+    }
+
+    async fn reply(&self, text: &str) {
+        self.replies.lock().unwrap().push(text.to_owned());
+    }
+}
+
+fn test_config() -> Config {
+    Config {
+        pishock_config: PishockConfig {
+            api_name: "test".to_owned(),
+            api_username: "test".to_owned(),
+            api_key: "test".to_owned(),
+            share_code: "test".to_owned(),
+        },
+        operator_ids: vec![999],
+        trigger_words: vec![TriggerWord {
+            word: "zap".to_owned(),
+            intensity: None,
+            duration_secs: None,
+        }],
+        cooldown_segment_duration: 60,
+        max_shocks_per_segment: 2,
+        database_config: None,
+        metrics_port: None,
+        default_intensity: 40,
+        default_duration_secs: 1,
+        control_socket_path: None,
+        escalation: None,
+    }
+}
+
+#[tokio::test]
+async fn no_trigger_does_not_shock() {
+    let config = test_config();
+    let mut cooldowns = HashMap::new();
+    let msg = MockMessage::new(1, "hello there");
+
+    let decision = evaluate(&msg, &config, &mut cooldowns, false).await;
+
+    assert!(matches!(decision, Decision::NoMatch));
+    assert!(cooldowns.is_empty());
+}
+
+#[tokio::test]
+async fn trigger_word_shocks() {
+    let config = test_config();
+    let mut cooldowns = HashMap::new();
+    let msg = MockMessage::new(1, "please zap me");
+
+    let decision = evaluate(&msg, &config, &mut cooldowns, false).await;
+
+    match decision {
+        Decision::Shock {
+            cause,
+            trigger_word,
+            ..
+        } => {
+            assert_eq!(cause, "trigger_word");
+            assert_eq!(trigger_word.as_deref(), Some("zap"));
+        }
+        _ => panic!("expected a shock decision"),
+    }
+    assert_eq!(cooldowns.get(&1).unwrap().shock_count(), 1);
+}
+
+#[tokio::test]
+async fn paused_bot_never_shocks() {
+    let config = test_config();
+    let mut cooldowns = HashMap::new();
+    let msg = MockMessage::new(1, "please zap me");
+
+    let decision = evaluate(&msg, &config, &mut cooldowns, true).await;
+
+    assert!(matches!(decision, Decision::NoMatch));
+}
+
+#[tokio::test]
+async fn segment_cap_rejects_until_reset() {
+    let config = test_config();
+    let mut cooldowns = HashMap::new();
+    let msg = MockMessage::new(1, "please zap me");
+
+    for _ in 0..config.max_shocks_per_segment {
+        let decision = evaluate(&msg, &config, &mut cooldowns, false).await;
+        assert!(matches!(decision, Decision::Shock { .. }));
+    }
+
+    let decision = evaluate(&msg, &config, &mut cooldowns, false).await;
+    assert!(matches!(decision, Decision::CooldownRejected { .. }));
+}
+
+#[tokio::test]
+async fn resetting_the_segment_allows_shocks_again() {
+    let config = test_config();
+    let mut cooldowns = HashMap::new();
+    let msg = MockMessage::new(1, "please zap me");
+
+    // Seed an already-expired cooldown segment, as if it were loaded from a restart.
+    cooldowns.insert(
+        1,
+        ShockCooldown::from_persisted(
+            chrono::Utc::now() - chrono::Duration::seconds(120),
+            config.max_shocks_per_segment,
+        ),
+    );
+
+    let decision = evaluate(&msg, &config, &mut cooldowns, false).await;
+
+    assert!(matches!(decision, Decision::Shock { .. }));
+}
+
+#[tokio::test]
+async fn trigger_word_override_is_carried_in_the_decision() {
+    let mut config = test_config();
+    config.trigger_words.push(TriggerWord {
+        word: "nuke".to_owned(),
+        intensity: Some(80),
+        duration_secs: Some(5),
+    });
+    let mut cooldowns = HashMap::new();
+    let msg = MockMessage::new(1, "please nuke me");
+
+    let decision = evaluate(&msg, &config, &mut cooldowns, false).await;
+
+    match decision {
+        Decision::Shock {
+            intensity_override,
+            duration_override,
+            ..
+        } => {
+            assert_eq!(intensity_override, Some(80));
+            assert_eq!(duration_override, Some(5));
+        }
+        _ => panic!("expected a shock decision"),
+    }
+}
+
+#[tokio::test]
+async fn shocks_this_segment_counts_up_before_the_current_shock() {
+    let config = test_config();
+    let mut cooldowns = HashMap::new();
+    let msg = MockMessage::new(1, "please zap me");
+
+    let first = evaluate(&msg, &config, &mut cooldowns, false).await;
+    let second = evaluate(&msg, &config, &mut cooldowns, false).await;
+
+    assert!(matches!(
+        first,
+        Decision::Shock {
+            shocks_this_segment: 0,
+            ..
+        }
+    ));
+    assert!(matches!(
+        second,
+        Decision::Shock {
+            shocks_this_segment: 1,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn escalation_ramps_intensity_up_to_a_clamped_max() {
+    let escalation = EscalationConfig {
+        step: 10,
+        max_intensity: 25,
+    };
+
+    assert_eq!(escalate_intensity(10, 0, &escalation), 10);
+    assert_eq!(escalate_intensity(10, 1, &escalation), 20);
+    assert_eq!(escalate_intensity(10, 5, &escalation), 25);
+}
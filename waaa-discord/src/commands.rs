@@ -0,0 +1,325 @@
+use log::{info, warn};
+use serenity::{
+    all::{
+        CommandDataOption, CommandDataOptionValue, CommandInteraction, CommandOptionType,
+        CreateCommand, CreateCommandOption, CreateInteractionResponse,
+        CreateInteractionResponseMessage, UserId,
+    },
+    client::Context,
+};
+use waaa_core::config::TriggerWord;
+
+use crate::{context, db};
+
+/// Builds the application commands operators use to manage the bot from inside Discord.
+pub(crate) fn build_commands() -> Vec<CreateCommand> {
+    vec![
+        CreateCommand::new("trigger")
+            .description("Manage trigger words.")
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::SubCommand, "add", "Add a trigger word.")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::String, "word", "The word to add.")
+                            .required(true),
+                    ),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "remove",
+                    "Remove a trigger word.",
+                )
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::String, "word", "The word to remove.")
+                        .required(true),
+                ),
+            )
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "list",
+                "List all trigger words.",
+            )),
+        CreateCommand::new("cooldown")
+            .description("Inspect or reset a user's shock cooldown.")
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::SubCommand, "show", "Show a user's cooldown.")
+                    .add_sub_option(
+                        CreateCommandOption::new(CommandOptionType::User, "user", "The user to inspect.")
+                            .required(true),
+                    ),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "reset",
+                    "Reset a user's cooldown.",
+                )
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::User, "user", "The user to reset.")
+                        .required(true),
+                ),
+            ),
+        CreateCommand::new("intensity")
+            .description("Set the default shock intensity and duration.")
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "intensity",
+                    "Intensity from 0-100.",
+                )
+                .min_int_value(0)
+                .max_int_value(100)
+                .required(true),
+            )
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::Integer, "seconds", "Duration in seconds.")
+                    .required(true),
+            ),
+        CreateCommand::new("test").description("Fire a single test shock to verify the PiShock link."),
+    ]
+}
+
+/// Handles a slash command interaction, gating every command on the invoker being listed in
+/// `Config.operator_ids` and always replying ephemerally.
+pub(crate) async fn handle(ctx: Context, command: CommandInteraction) {
+    let is_operator = ctx
+        .data
+        .read()
+        .await
+        .get::<context::Config>()
+        .unwrap()
+        .operator_ids
+        .contains(&command.user.id.get());
+
+    let message = if !is_operator {
+        "You are not authorized to use this command.".to_owned()
+    } else {
+        match command.data.name.as_str() {
+            "trigger" => handle_trigger(&ctx, &command).await,
+            "cooldown" => handle_cooldown(&ctx, &command).await,
+            "intensity" => handle_intensity(&ctx, &command).await,
+            "test" => handle_test(&ctx, &command).await,
+            other => format!("Unknown command \"{other}\"."),
+        }
+    };
+
+    reply(&ctx, &command, &message).await;
+}
+
+async fn reply(ctx: &Context, command: &CommandInteraction, message: &str) {
+    let response = CreateInteractionResponseMessage::new()
+        .content(message)
+        .ephemeral(true);
+
+    if let Err(e) = command
+        .create_response(&ctx.http, CreateInteractionResponse::Message(response))
+        .await
+    {
+        warn!("Error replying to slash command: {e}");
+    }
+}
+
+async fn handle_trigger(ctx: &Context, command: &CommandInteraction) -> String {
+    let Some(sub) = command.data.options.first() else {
+        return "Usage: /trigger add|remove|list".to_owned();
+    };
+
+    match (sub.name.as_str(), &sub.value) {
+        ("add", CommandDataOptionValue::SubCommand(options)) => {
+            let Some(word) = string_option(options, "word") else {
+                return "Usage: /trigger add <word>".to_owned();
+            };
+            let word = word.to_lowercase();
+            ctx.data
+                .write()
+                .await
+                .get_mut::<context::Config>()
+                .unwrap()
+                .trigger_words
+                .push(TriggerWord {
+                    word: word.clone(),
+                    intensity: None,
+                    duration_secs: None,
+                });
+            info!("Added trigger word \"{word}\" via slash command.");
+            format!("Added trigger word \"{word}\".")
+        }
+        ("remove", CommandDataOptionValue::SubCommand(options)) => {
+            let Some(word) = string_option(options, "word") else {
+                return "Usage: /trigger remove <word>".to_owned();
+            };
+            let word = word.to_lowercase();
+            ctx.data
+                .write()
+                .await
+                .get_mut::<context::Config>()
+                .unwrap()
+                .trigger_words
+                .retain(|x| x.word != word);
+            info!("Removed trigger word \"{word}\" via slash command.");
+            format!("Removed trigger word \"{word}\".")
+        }
+        ("list", _) => {
+            let data = ctx.data.read().await;
+            let words = &data.get::<context::Config>().unwrap().trigger_words;
+            if words.is_empty() {
+                "No trigger words configured.".to_owned()
+            } else {
+                let listed: Vec<String> = words
+                    .iter()
+                    .map(|t| match (t.intensity, t.duration_secs) {
+                        (None, None) => t.word.clone(),
+                        (intensity, duration_secs) => format!(
+                            "{} (intensity={:?}, duration_secs={:?})",
+                            t.word, intensity, duration_secs
+                        ),
+                    })
+                    .collect();
+                format!("Trigger words: {}", listed.join(", "))
+            }
+        }
+        _ => "Unknown /trigger subcommand.".to_owned(),
+    }
+}
+
+async fn handle_cooldown(ctx: &Context, command: &CommandInteraction) -> String {
+    let Some(sub) = command.data.options.first() else {
+        return "Usage: /cooldown show|reset <user>".to_owned();
+    };
+
+    let options = match &sub.value {
+        CommandDataOptionValue::SubCommand(options) => options,
+        _ => return "Unknown /cooldown subcommand.".to_owned(),
+    };
+
+    let Some(user_id) = user_option(options, "user") else {
+        return "Usage: /cooldown show|reset <user>".to_owned();
+    };
+
+    match sub.name.as_str() {
+        "show" => {
+            let data = ctx.data.read().await;
+            let config = data.get::<context::Config>().unwrap();
+            match data
+                .get::<context::UserShockCooldowns>()
+                .unwrap()
+                .get(&user_id.get())
+            {
+                Some(cooldown) => format!(
+                    "<@{user_id}> has dealt {}/{} shocks in the current segment.",
+                    cooldown.shock_count(),
+                    config.max_shocks_per_segment,
+                ),
+                None => format!("<@{user_id}> has no active cooldown."),
+            }
+        }
+        "reset" => {
+            ctx.data
+                .write()
+                .await
+                .get_mut::<context::UserShockCooldowns>()
+                .unwrap()
+                .remove(&user_id.get());
+            info!("Reset cooldown for user {user_id} via slash command.");
+            format!("Reset cooldown for <@{user_id}>.")
+        }
+        other => format!("Unknown /cooldown subcommand \"{other}\"."),
+    }
+}
+
+async fn handle_intensity(ctx: &Context, command: &CommandInteraction) -> String {
+    let Some(intensity) = integer_option(&command.data.options, "intensity") else {
+        return "Usage: /intensity <n> <seconds>".to_owned();
+    };
+    let Some(seconds) = integer_option(&command.data.options, "seconds") else {
+        return "Usage: /intensity <n> <seconds>".to_owned();
+    };
+
+    if !(0..=100).contains(&intensity) {
+        return "Intensity must be between 0 and 100.".to_owned();
+    }
+
+    let mut data = ctx.data.write().await;
+    let config = data.get_mut::<context::Config>().unwrap();
+    config.default_intensity = intensity as u32;
+    config.default_duration_secs = seconds as u32;
+
+    info!("Set default intensity to {intensity} for {seconds}s via slash command.");
+    format!("Default intensity set to {intensity} for {seconds} seconds.")
+}
+
+/// Fires a single shock at `config.default_intensity`/`default_duration_secs` to verify the
+/// PiShock link, going through the same metrics/audit-log bookkeeping as a triggered shock so
+/// there's no un-audited path to the device.
+async fn handle_test(ctx: &Context, command: &CommandInteraction) -> String {
+    let (shocker, db_pool, metrics, intensity, duration) = {
+        let data = ctx.data.read().await;
+        let config = data.get::<context::Config>().unwrap();
+        (
+            data.get::<context::Shocker>().unwrap().clone(),
+            data.get::<context::DbPool>().unwrap().clone(),
+            data.get::<context::Metrics>().unwrap().clone(),
+            config.default_intensity,
+            std::time::Duration::from_secs(config.default_duration_secs as u64),
+        )
+    };
+
+    let shock_start = std::time::Instant::now();
+    let result = shocker.shock(intensity, duration).await;
+    let latency = shock_start.elapsed();
+
+    if let Err(e) = result {
+        return format!("Test shock failed: {e}");
+    }
+
+    metrics.shocks_total.with_label_values(&["test"]).inc();
+    metrics.shock_latency.observe(latency.as_secs_f64());
+
+    if let Some(pool) = &db_pool {
+        if let Err(e) = db::record_shock_event(
+            pool,
+            command.user.id.get(),
+            command.channel_id,
+            command.guild_id,
+            None,
+            intensity,
+            duration,
+        )
+        .await
+        {
+            warn!("Could not record shock event: {e}");
+        }
+    }
+
+    "Test shock sent.".to_owned()
+}
+
+fn string_option<'a>(options: &'a [CommandDataOption], name: &str) -> Option<&'a str> {
+    options.iter().find(|o| o.name == name).and_then(|o| {
+        if let CommandDataOptionValue::String(s) = &o.value {
+            Some(s.as_str())
+        } else {
+            None
+        }
+    })
+}
+
+fn integer_option(options: &[CommandDataOption], name: &str) -> Option<i64> {
+    options.iter().find(|o| o.name == name).and_then(|o| {
+        if let CommandDataOptionValue::Integer(n) = &o.value {
+            Some(*n)
+        } else {
+            None
+        }
+    })
+}
+
+fn user_option(options: &[CommandDataOption], name: &str) -> Option<UserId> {
+    options.iter().find(|o| o.name == name).and_then(|o| {
+        if let CommandDataOptionValue::User(user_id) = &o.value {
+            Some(*user_id)
+        } else {
+            None
+        }
+    })
+}
@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use pishock_rs::PiShocker;
+use serenity::prelude::*;
+use waaa_core::cooldown::ShockCooldown;
+
+use crate::db;
+
+pub(crate) struct Shocker;
+impl TypeMapKey for Shocker {
+    type Value = PiShocker;
+}
+
+pub(crate) struct Config;
+impl TypeMapKey for Config {
+    type Value = waaa_core::config::Config;
+}
+
+/// Keyed by the platform-neutral user ID ([`waaa_core::platform::ChatPlatform::user_id`]).
+pub(crate) struct UserShockCooldowns;
+impl TypeMapKey for UserShockCooldowns {
+    type Value = HashMap<u64, ShockCooldown>;
+}
+
+/// The Postgres connection pool backing shock cooldown persistence and the shock audit log.
+/// `None` when `database_config` is omitted, in which case the whole subsystem is a no-op.
+pub(crate) struct DbPool;
+impl TypeMapKey for DbPool {
+    type Value = Option<db::DbPool>;
+}
+
+pub(crate) struct Metrics;
+impl TypeMapKey for Metrics {
+    type Value = crate::metrics::Metrics;
+}
+
+/// Gates all shocks regardless of triggers when set, toggled by the operator control socket.
+pub(crate) struct Paused;
+impl TypeMapKey for Paused {
+    type Value = bool;
+}
@@ -0,0 +1,149 @@
+use std::{path::Path, sync::Arc};
+
+use log::{error, info, warn};
+use serenity::prelude::{RwLock, TypeMap};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{UnixListener, UnixStream},
+};
+use waaa_core::config::TriggerWord;
+
+use crate::context;
+
+/// Listens on the Unix domain socket at `socket_path` for line-based operator commands,
+/// mutating the shared bot state in `data` so the bot can be tuned without a restart.
+///
+/// Supported commands, one per line:
+/// * `pause` / `resume` - gate or ungate all shocks regardless of triggers.
+/// * `reload` - re-read `config.ron` and swap the live `Config`.
+/// * `add-trigger <word>` / `remove-trigger <word>` - edit the trigger word list.
+/// * `set-intensity <n>` - set the default shock intensity.
+/// * `clear-cooldown <user_id>` - drop a user's cooldown segment.
+pub(crate) async fn listen(socket_path: String, data: Arc<RwLock<TypeMap>>) {
+    if Path::new(&socket_path).exists() {
+        if let Err(e) = tokio::fs::remove_file(&socket_path).await {
+            warn!("Could not remove stale control socket at \"{socket_path}\": {e}");
+        }
+    }
+
+    let listener = match UnixListener::bind(&socket_path) {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("Could not bind control socket at \"{socket_path}\": {e}");
+            return;
+        }
+    };
+
+    info!("Listening for operator commands on \"{socket_path}\".");
+
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                tokio::spawn(handle_connection(stream, data.clone()));
+            }
+            Err(e) => warn!("Error accepting control socket connection: {e}"),
+        }
+    }
+}
+
+async fn handle_connection(stream: UnixStream, data: Arc<RwLock<TypeMap>>) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Ok(Some(line)) = lines.next_line().await {
+        let reply = handle_command(&line, &data).await;
+        if let Err(e) = writer.write_all(format!("{reply}\n").as_bytes()).await {
+            warn!("Error writing control socket reply: {e}");
+            break;
+        }
+    }
+}
+
+async fn handle_command(line: &str, data: &Arc<RwLock<TypeMap>>) -> String {
+    let mut parts = line.split_whitespace();
+    let command = match parts.next() {
+        Some(command) => command,
+        None => return "error: empty command".to_owned(),
+    };
+
+    match command {
+        "pause" => {
+            *data.write().await.get_mut::<context::Paused>().unwrap() = true;
+            info!("Paused via control socket.");
+            "ok: paused".to_owned()
+        }
+        "resume" => {
+            *data.write().await.get_mut::<context::Paused>().unwrap() = false;
+            info!("Resumed via control socket.");
+            "ok: resumed".to_owned()
+        }
+        "reload" => match crate::get_config().await {
+            Ok(new_config) => {
+                *data.write().await.get_mut::<context::Config>().unwrap() = new_config;
+                info!("Reloaded \"config.ron\" via control socket.");
+                "ok: reloaded".to_owned()
+            }
+            Err(e) => format!("error: could not reload config: {e}"),
+        },
+        "add-trigger" => match parts.next() {
+            Some(word) => {
+                let word = word.to_lowercase();
+                data.write()
+                    .await
+                    .get_mut::<context::Config>()
+                    .unwrap()
+                    .trigger_words
+                    .push(TriggerWord {
+                        word: word.clone(),
+                        intensity: None,
+                        duration_secs: None,
+                    });
+                info!("Added trigger word \"{word}\" via control socket.");
+                format!("ok: added trigger \"{word}\"")
+            }
+            None => "error: usage: add-trigger <word>".to_owned(),
+        },
+        "remove-trigger" => match parts.next() {
+            Some(word) => {
+                let word = word.to_lowercase();
+                data.write()
+                    .await
+                    .get_mut::<context::Config>()
+                    .unwrap()
+                    .trigger_words
+                    .retain(|x| x.word != word);
+                info!("Removed trigger word \"{word}\" via control socket.");
+                format!("ok: removed trigger \"{word}\"")
+            }
+            None => "error: usage: remove-trigger <word>".to_owned(),
+        },
+        "set-intensity" => match parts.next().and_then(|n| n.parse::<u32>().ok()) {
+            Some(intensity) if intensity > 100 => {
+                "error: intensity must be between 0 and 100".to_owned()
+            }
+            Some(intensity) => {
+                data.write()
+                    .await
+                    .get_mut::<context::Config>()
+                    .unwrap()
+                    .default_intensity = intensity;
+                info!("Set default intensity to {intensity} via control socket.");
+                format!("ok: default intensity set to {intensity}")
+            }
+            None => "error: usage: set-intensity <n>".to_owned(),
+        },
+        "clear-cooldown" => match parts.next().and_then(|id| id.parse::<u64>().ok()) {
+            Some(user_id) => {
+                data.write()
+                    .await
+                    .get_mut::<context::UserShockCooldowns>()
+                    .unwrap()
+                    .remove(&user_id);
+                info!("Cleared cooldown for user {user_id} via control socket.");
+                format!("ok: cleared cooldown for {user_id}")
+            }
+            None => "error: usage: clear-cooldown <user_id>".to_owned(),
+        },
+        other => format!("error: unknown command \"{other}\""),
+    }
+}
@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use bb8::Pool;
+use bb8_postgres::PostgresConnectionManager;
+use chrono::{DateTime, Utc};
+use log::info;
+use serenity::all::{ChannelId, GuildId};
+use thiserror::Error;
+use tokio_postgres::NoTls;
+use waaa_core::{config::DatabaseConfig, cooldown::ShockCooldown};
+
+pub(crate) type DbPool = Pool<PostgresConnectionManager<NoTls>>;
+
+#[derive(Debug, Error)]
+pub(crate) enum DbError {
+    #[error("Error checking out a connection from the pool.")]
+    Pool(#[from] bb8::RunError<tokio_postgres::Error>),
+    #[error("Postgres error.")]
+    Postgres(#[from] tokio_postgres::Error),
+}
+
+/// Builds the `bb8` connection pool for `config` and ensures the `shock_cooldowns` and
+/// `shock_events` tables exist, creating them on first run.
+pub(crate) async fn init_pool(config: &DatabaseConfig) -> Result<DbPool, DbError> {
+    // Built programmatically, rather than a hand-formatted libpq keyword/value string, so a
+    // `host`/`user`/`password`/`dbname` containing whitespace or quotes doesn't need escaping.
+    let mut pg_config = tokio_postgres::Config::new();
+    pg_config
+        .host(&config.host)
+        .port(config.port)
+        .user(&config.user)
+        .password(&config.password)
+        .dbname(&config.dbname);
+
+    let manager = PostgresConnectionManager::new(pg_config, NoTls);
+
+    let pool = Pool::builder().build(manager).await?;
+
+    pool.get()
+        .await?
+        .batch_execute(
+            "CREATE TABLE IF NOT EXISTS shock_cooldowns (
+                user_id BIGINT PRIMARY KEY,
+                segment_start TIMESTAMPTZ NOT NULL,
+                shock_count INT NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS shock_events (
+                id BIGSERIAL PRIMARY KEY,
+                user_id BIGINT NOT NULL,
+                channel_id BIGINT NOT NULL,
+                guild_id BIGINT,
+                trigger_word TEXT,
+                intensity BIGINT NOT NULL,
+                duration_ms INT NOT NULL,
+                occurred_at TIMESTAMPTZ NOT NULL DEFAULT now()
+            );",
+        )
+        .await?;
+
+    info!("Connected to Postgres and ensured shock persistence tables exist.");
+
+    Ok(pool)
+}
+
+/// Loads a user's in-progress cooldown segment, if one has been persisted.
+pub(crate) async fn load_cooldown(
+    pool: &DbPool,
+    user_id: u64,
+) -> Result<Option<ShockCooldown>, DbError> {
+    let conn = pool.get().await?;
+
+    let row = conn
+        .query_opt(
+            "SELECT segment_start, shock_count FROM shock_cooldowns WHERE user_id = $1",
+            &[&(user_id as i64)],
+        )
+        .await?;
+
+    Ok(row.map(|row| {
+        let segment_start: DateTime<Utc> = row.get(0);
+        let shock_count: i32 = row.get(1);
+        ShockCooldown::from_persisted(segment_start, shock_count as u32)
+    }))
+}
+
+/// Upserts a user's current cooldown segment so a restart resumes mid-segment.
+pub(crate) async fn save_cooldown(
+    pool: &DbPool,
+    user_id: u64,
+    cooldown: &ShockCooldown,
+) -> Result<(), DbError> {
+    let conn = pool.get().await?;
+
+    conn.execute(
+        "INSERT INTO shock_cooldowns (user_id, segment_start, shock_count)
+         VALUES ($1, $2, $3)
+         ON CONFLICT (user_id) DO UPDATE SET segment_start = $2, shock_count = $3",
+        &[
+            &(user_id as i64),
+            &cooldown.segment_start(),
+            &(cooldown.shock_count() as i32),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Records one row in the `shock_events` audit log for a successful shock.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn record_shock_event(
+    pool: &DbPool,
+    user_id: u64,
+    channel_id: ChannelId,
+    guild_id: Option<GuildId>,
+    trigger_word: Option<&str>,
+    intensity: u32,
+    duration: Duration,
+) -> Result<(), DbError> {
+    let conn = pool.get().await?;
+
+    conn.execute(
+        "INSERT INTO shock_events (user_id, channel_id, guild_id, trigger_word, intensity, duration_ms)
+         VALUES ($1, $2, $3, $4, $5, $6)",
+        &[
+            &(user_id as i64),
+            &(channel_id.get() as i64),
+            &guild_id.map(|g| g.get() as i64),
+            &trigger_word,
+            &(intensity as i64),
+            &(duration.as_millis() as i32),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
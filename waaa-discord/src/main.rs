@@ -1,25 +1,28 @@
-mod config;
+mod commands;
 mod context;
-mod shock;
+mod control;
+mod db;
+mod message_shock;
+mod metrics;
 
 use std::collections::HashMap;
 
 use log::{debug, error, info, warn};
+use message_shock::word_shock;
 use pishock_rs::{
     errors::PiShockError::{ShockerOffline, ShockerPaused},
     PiShockAccount, PiShocker,
 };
 use ron::error::SpannedError;
+use serde::Deserialize;
 use serenity::{
-    all::{GatewayIntents, Message, Ready},
+    all::{GatewayIntents, Interaction, Message, Ready},
     async_trait,
     client::{Context, EventHandler},
     Client,
 };
-use shock::word_shock;
 use thiserror::Error;
-
-use crate::config::Config;
+use waaa_core::config::Config;
 
 struct Handler;
 
@@ -29,8 +32,24 @@ impl EventHandler for Handler {
         word_shock(ctx, msg).await;
     }
 
-    async fn ready(&self, _: Context, ready: Ready) {
+    async fn ready(&self, ctx: Context, ready: Ready) {
         info!("{} is ready!", ready.user.name);
+
+        for guild in &ready.guilds {
+            if let Err(e) = guild
+                .id
+                .set_commands(&ctx.http, commands::build_commands())
+                .await
+            {
+                warn!("Error registering slash commands for guild {}: {e}", guild.id);
+            }
+        }
+    }
+
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        if let Interaction::Command(command) = interaction {
+            commands::handle(ctx, command).await;
+        }
     }
 }
 
@@ -65,16 +84,47 @@ async fn main() {
 
     debug!("{config:?}");
 
+    // `bot_token` is Discord-specific and lives outside the protocol-agnostic `Config`, so it's
+    // read from "config.ron" separately rather than being stored in shared bot state.
+    let mut bot_token: Option<String> = None;
+    match get_bot_token().await {
+        Ok(o) => bot_token = Some(o),
+        Err(e) => match e {
+            GetConfigError::IO(io) => log_panic("Error reading \"config.ron\" for the bot token.", io),
+            GetConfigError::Spanned(spanned) => {
+                log_panic("Error parsing \"config.ron\" for the bot token.", spanned)
+            }
+        },
+    }
+    let bot_token = bot_token.unwrap();
+
     // Get the shocker from the config.
     let shocker = get_shocker(&config).await;
 
+    // Build the database connection pool, if persistence is configured. Left `None` if the
+    // `database_config` section is omitted, which makes the whole subsystem a no-op.
+    let mut db_pool = None;
+    if let Some(database_config) = &config.database_config {
+        match db::init_pool(database_config).await {
+            Ok(pool) => db_pool = Some(pool),
+            Err(e) => log_panic("Error initializing database connection pool.", e),
+        }
+    }
+
+    // Build the Prometheus registry and, if a port is configured, serve it over HTTP.
+    let metrics = metrics::Metrics::new();
+    if let Some(metrics_port) = config.metrics_port {
+        let registry = metrics.registry.clone();
+        tokio::spawn(metrics::serve(registry, metrics_port));
+    }
+
     // Build gateway intents.
     let gateway_intents = GatewayIntents::GUILD_MESSAGES
         | GatewayIntents::DIRECT_MESSAGES
         | GatewayIntents::MESSAGE_CONTENT;
 
     // Build the client.
-    let mut client = Client::builder(&config.discord_config.bot_token, gateway_intents)
+    let mut client = Client::builder(&bot_token, gateway_intents)
         .event_handler(Handler)
         .await
         .expect("Error creating Discord client.");
@@ -92,12 +142,22 @@ async fn main() {
         });
     }
 
+    // Spawn a listener for the operator control socket, if configured, so the bot can be
+    // paused, reloaded, and tuned live without dropping the gateway connection.
+    if let Some(socket_path) = config.control_socket_path.clone() {
+        let data = client.data.clone();
+        tokio::spawn(control::listen(socket_path, data));
+    }
+
     // Initialize client data to be shared across command invocations and shards.
     {
         let mut data = client.data.write().await;
         data.insert::<context::Shocker>(shocker);
         data.insert::<context::Config>(config);
         data.insert::<context::UserShockCooldowns>(HashMap::new());
+        data.insert::<context::DbPool>(db_pool);
+        data.insert::<context::Metrics>(metrics);
+        data.insert::<context::Paused>(false);
     }
 
     // Start the client.
@@ -118,7 +178,9 @@ fn initialize_log() {
             ))
         })
         .level(log::LevelFilter::Warn)
-        .level_for(env!("CARGO_PKG_NAME"), log::LevelFilter::Trace)
+        // Matched against `record.target()`, which is rooted at the `[[bin]]` name ("waaa"),
+        // not `CARGO_PKG_NAME` ("waaa-discord").
+        .level_for("waaa", log::LevelFilter::Trace)
         .chain(std::io::stdout())
         .chain(fern::log_file("output.log").unwrap())
         .apply()
@@ -131,7 +193,7 @@ fn initialize_log() {
     );
 }
 
-async fn get_shocker(config: &config::Config) -> PiShocker {
+async fn get_shocker(config: &Config) -> PiShocker {
     debug!("Fetching PiShock account.");
     let account = PiShockAccount::new(
         config.pishock_config.api_name.clone(),
@@ -166,8 +228,21 @@ enum GetConfigError {
     Spanned(#[from] SpannedError),
 }
 
-async fn get_config() -> Result<config::Config, GetConfigError> {
-    Ok(ron::from_str::<config::Config>(
+pub(crate) async fn get_config() -> Result<Config, GetConfigError> {
+    Ok(ron::from_str::<Config>(
         &tokio::fs::read_to_string("config.ron").await?,
     )?)
 }
+
+/// The Discord-specific half of "config.ron", alongside the protocol-agnostic `Config` fields.
+#[derive(Debug, Deserialize)]
+struct DiscordFrontendConfig {
+    bot_token: String,
+}
+
+async fn get_bot_token() -> Result<String, GetConfigError> {
+    Ok(ron::from_str::<DiscordFrontendConfig>(
+        &tokio::fs::read_to_string("config.ron").await?,
+    )?
+    .bot_token)
+}
@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use log::warn;
+use serenity::{
+    all::{Message, UserId},
+    client::Context,
+};
+use waaa_core::{platform::ChatPlatform, shock::ShockOutcome};
+
+use crate::{context, db};
+
+/// Adapts a Serenity [`Message`] onto [`ChatPlatform`] so the core shock/cooldown logic can
+/// drive this frontend the same way it would any other chat platform.
+struct DiscordMessage<'a> {
+    ctx: &'a Context,
+    msg: &'a Message,
+}
+
+#[async_trait]
+impl ChatPlatform for DiscordMessage<'_> {
+    fn user_id(&self) -> u64 {
+        self.msg.author.id.get()
+    }
+
+    fn content(&self) -> &str {
+        &self.msg.content
+    }
+
+    fn mentions_user(&self, user_id: u64) -> bool {
+        self.msg
+            .mentions_user_id(<u64 as Into<UserId>>::into(user_id))
+    }
+
+    async fn reply(&self, text: &str) {
+        if let Err(e) = self.msg.channel_id.say(&self.ctx.http, text).await {
+            warn!("Error sending reply: {e}");
+        }
+    }
+}
+
+pub(crate) async fn word_shock(ctx: Context, msg: Message) {
+    let (config, shocker, db_pool, metrics, paused) = {
+        let data = ctx.data.read().await;
+        (
+            data.get::<context::Config>().unwrap().clone(),
+            data.get::<context::Shocker>().unwrap().clone(),
+            data.get::<context::DbPool>().unwrap().clone(),
+            data.get::<context::Metrics>().unwrap().clone(),
+            *data.get::<context::Paused>().unwrap(),
+        )
+    };
+
+    let platform_msg = DiscordMessage {
+        ctx: &ctx,
+        msg: &msg,
+    };
+
+    // Cheap pre-check so a message with no chance of shocking never pays for a Postgres round
+    // trip, and so that round trip (when it does happen) isn't made while holding the shared
+    // state write lock that every other shard's message handling also needs.
+    if !waaa_core::shock::would_match(&platform_msg, &config, paused) {
+        return;
+    }
+
+    let user_id = msg.author.id.get();
+
+    // If the user isn't cached in memory yet, try to resume their cooldown segment from the
+    // database so a restart doesn't give everyone a fresh segment.
+    let cached = ctx
+        .data
+        .read()
+        .await
+        .get::<context::UserShockCooldowns>()
+        .unwrap()
+        .contains_key(&user_id);
+    if !cached {
+        if let Some(pool) = &db_pool {
+            match db::load_cooldown(pool, user_id).await {
+                Ok(Some(persisted)) => {
+                    ctx.data
+                        .write()
+                        .await
+                        .get_mut::<context::UserShockCooldowns>()
+                        .unwrap()
+                        .insert(user_id, persisted);
+                }
+                Ok(None) => {}
+                Err(e) => warn!("Could not load persisted shock cooldown: {e}"),
+            }
+        }
+    }
+
+    let typing = msg.channel_id.start_typing(&ctx.http);
+    let outcome = {
+        let mut data = ctx.data.write().await;
+        let cooldowns = data.get_mut::<context::UserShockCooldowns>().unwrap();
+        waaa_core::shock::decide_and_shock(&platform_msg, &config, cooldowns, &shocker, paused)
+            .await
+    };
+    typing.stop();
+
+    let tracked_users = ctx
+        .data
+        .read()
+        .await
+        .get::<context::UserShockCooldowns>()
+        .unwrap()
+        .len() as i64;
+    metrics.tracked_users.set(tracked_users);
+
+    match outcome {
+        ShockOutcome::NoMatch => {}
+        ShockOutcome::CooldownRejected { .. } => {
+            metrics.cooldown_rejected_total.inc();
+        }
+        ShockOutcome::Shocked {
+            cause,
+            trigger_word,
+            intensity,
+            duration,
+            latency,
+        } => {
+            metrics.shocks_total.with_label_values(&[cause]).inc();
+            metrics.shock_latency.observe(latency.as_secs_f64());
+
+            if let Some(pool) = &db_pool {
+                let cooldown = ctx
+                    .data
+                    .read()
+                    .await
+                    .get::<context::UserShockCooldowns>()
+                    .unwrap()
+                    .get(&user_id)
+                    .unwrap()
+                    .clone();
+
+                if let Err(e) = db::save_cooldown(pool, user_id, &cooldown).await {
+                    warn!("Could not persist shock cooldown: {e}");
+                }
+                if let Err(e) = db::record_shock_event(
+                    pool,
+                    user_id,
+                    msg.channel_id,
+                    msg.guild_id,
+                    trigger_word.as_deref(),
+                    intensity,
+                    duration,
+                )
+                .await
+                {
+                    warn!("Could not record shock event: {e}");
+                }
+            }
+        }
+    }
+}
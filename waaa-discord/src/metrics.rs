@@ -0,0 +1,83 @@
+use log::{error, info};
+use prometheus::{Histogram, HistogramOpts, IntCounter, IntCounterVec, IntGauge, Opts, Registry};
+use warp::Filter;
+
+/// Prometheus counters and histograms tracking shock activity, served over HTTP at `/metrics`.
+#[derive(Clone)]
+pub(crate) struct Metrics {
+    pub registry: Registry,
+    /// Total successful shocks, labeled by whether a `trigger_word` or a `mention` caused them.
+    pub shocks_total: IntCounterVec,
+    /// Shock attempts rejected because the user was still in their cooldown segment.
+    pub cooldown_rejected_total: IntCounter,
+    /// Number of users currently tracked in `UserShockCooldowns`.
+    pub tracked_users: IntGauge,
+    /// Wall-clock latency of calls to `shocker.shock(...)`.
+    pub shock_latency: Histogram,
+}
+
+impl Metrics {
+    /// Builds and registers every metric. Call once at startup.
+    pub(crate) fn new() -> Self {
+        let registry = Registry::new();
+
+        let shocks_total = IntCounterVec::new(
+            Opts::new("waaa_shocks_total", "Total shocks dealt."),
+            &["cause"],
+        )
+        .unwrap();
+
+        let cooldown_rejected_total = IntCounter::new(
+            "waaa_cooldown_rejected_total",
+            "Shock attempts rejected because the user is in an active cooldown.",
+        )
+        .unwrap();
+
+        let tracked_users = IntGauge::new(
+            "waaa_tracked_users",
+            "Number of users currently tracked in the cooldown cache.",
+        )
+        .unwrap();
+
+        let shock_latency = Histogram::with_opts(
+            HistogramOpts::new(
+                "waaa_shock_latency_seconds",
+                "Latency of calls to the PiShock API.",
+            )
+            .buckets(vec![0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]),
+        )
+        .unwrap();
+
+        registry.register(Box::new(shocks_total.clone())).unwrap();
+        registry
+            .register(Box::new(cooldown_rejected_total.clone()))
+            .unwrap();
+        registry.register(Box::new(tracked_users.clone())).unwrap();
+        registry.register(Box::new(shock_latency.clone())).unwrap();
+
+        Self {
+            registry,
+            shocks_total,
+            cooldown_rejected_total,
+            tracked_users,
+            shock_latency,
+        }
+    }
+}
+
+/// Serves `registry` as `/metrics` on `0.0.0.0:<port>` until the process exits.
+pub(crate) async fn serve(registry: Registry, port: u16) {
+    let route = warp::path("metrics").map(move || {
+        use prometheus::Encoder;
+
+        let encoder = prometheus::TextEncoder::new();
+        let mut buffer = Vec::new();
+        if let Err(e) = encoder.encode(&registry.gather(), &mut buffer) {
+            error!("Error encoding Prometheus metrics: {e}");
+        }
+        buffer
+    });
+
+    info!("Serving Prometheus metrics on 0.0.0.0:{port}/metrics");
+    warp::serve(route).run(([0, 0, 0, 0], port)).await;
+}